@@ -1,15 +1,13 @@
-use std::sync::Arc;
+use sanity_rs_client::{
+    config::SanityConfig,
+    sanity_client::{AssetType, SanityClient},
+};
 
-use sanity_rs_client::{config::SanityConfig, sanity_client::SanityClient};
-
-// Have to manually create a blocking tokio context for upload_image function to work
-async fn upload_image(client: Arc<SanityClient>) {
-    let client = Arc::clone(&client);
-    let response = tokio::task::spawn_blocking(move || {
-        let res = client.upload_image(String::from("image.png"));
-        println!("{:?}", res);
-        return res;
-    }).await.unwrap();
+async fn upload_image(client: &SanityClient) {
+    let response = client
+        .upload_asset(String::from("image.png"), AssetType::Image, None, None)
+        .await;
+    println!("{:?}", response);
 }
 
 #[tokio::main]
@@ -25,7 +23,5 @@ async fn main() {
 
     let s_client = SanityClient::new(config);
 
-    let arc_client = Arc::new(s_client);
-
-    let upload = upload_image(arc_client).await;
+    upload_image(&s_client).await;
 }
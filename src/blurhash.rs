@@ -0,0 +1,162 @@
+use image::{DynamicImage, GenericImageView};
+
+const BASE83_CHARS: &[u8] =
+    b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz#$%*+,-.:;=?@[]^_{|}~";
+
+/// Encodes a BlurHash placeholder string for `image`, sampling `components_x` by
+/// `components_y` DCT components (each in `1..=9`).
+///
+/// The returned string can be stored alongside an uploaded asset and decoded client-side
+/// to render a blurred placeholder before the full image has loaded.
+pub fn encode(image: &DynamicImage, components_x: u32, components_y: u32) -> String {
+    assert!((1..=9).contains(&components_x), "components_x must be in 1..=9");
+    assert!((1..=9).contains(&components_y), "components_y must be in 1..=9");
+
+    let (width, height) = image.dimensions();
+    let rgb = image.to_rgb8();
+
+    let mut factors = Vec::with_capacity((components_x * components_y) as usize);
+
+    for j in 0..components_y {
+        for i in 0..components_x {
+            let normalisation = if i == 0 && j == 0 { 1.0 } else { 2.0 };
+            let scale = normalisation / (width as f64 * height as f64);
+
+            let mut r = 0.0;
+            let mut g = 0.0;
+            let mut b = 0.0;
+
+            for y in 0..height {
+                for x in 0..width {
+                    let basis = (std::f64::consts::PI * i as f64 * x as f64 / width as f64).cos()
+                        * (std::f64::consts::PI * j as f64 * y as f64 / height as f64).cos();
+
+                    let pixel = rgb.get_pixel(x, y);
+                    r += basis * srgb_to_linear(pixel[0] as f64 / 255.0);
+                    g += basis * srgb_to_linear(pixel[1] as f64 / 255.0);
+                    b += basis * srgb_to_linear(pixel[2] as f64 / 255.0);
+                }
+            }
+
+            factors.push((scale * r, scale * g, scale * b));
+        }
+    }
+
+    let dc = factors[0];
+    let ac = &factors[1..];
+
+    let size_flag = (components_x - 1) + (components_y - 1) * 9;
+    let mut result = encode_int(size_flag, 1);
+
+    let max_value = if !ac.is_empty() {
+        let actual_max = ac
+            .iter()
+            .flat_map(|(r, g, b)| [r.abs(), g.abs(), b.abs()])
+            .fold(0.0_f64, f64::max);
+
+        let quantised_max = ((actual_max * 166.0 - 0.5).floor().max(0.0) as u32).min(82);
+        result.push_str(&encode_int(quantised_max, 1));
+        (quantised_max as f64 + 1.0) / 166.0
+    } else {
+        result.push_str(&encode_int(0, 1));
+        1.0
+    };
+
+    result.push_str(&encode_dc(dc));
+
+    for component in ac {
+        result.push_str(&encode_ac(*component, max_value));
+    }
+
+    result
+}
+
+fn srgb_to_linear(c: f64) -> f64 {
+    if c <= 0.04045 {
+        c / 12.92
+    } else {
+        ((c + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+fn linear_to_srgb(c: f64) -> f64 {
+    let c = c.clamp(0.0, 1.0);
+    if c <= 0.0031308 {
+        (c * 12.92 * 255.0).round()
+    } else {
+        ((1.055 * c.powf(1.0 / 2.4) - 0.055) * 255.0).round()
+    }
+}
+
+fn encode_int(value: u32, length: usize) -> String {
+    let mut value = value;
+    let mut chars = vec![0u8; length];
+
+    for i in (0..length).rev() {
+        let digit = value % 83;
+        chars[i] = BASE83_CHARS[digit as usize];
+        value /= 83;
+    }
+
+    String::from_utf8(chars).unwrap()
+}
+
+fn encode_dc(color: (f64, f64, f64)) -> String {
+    let (r, g, b) = color;
+    let rounded_r = linear_to_srgb(r) as u32;
+    let rounded_g = linear_to_srgb(g) as u32;
+    let rounded_b = linear_to_srgb(b) as u32;
+
+    let value = (rounded_r << 16) + (rounded_g << 8) + rounded_b;
+    encode_int(value, 4)
+}
+
+fn encode_ac(color: (f64, f64, f64), max_value: f64) -> String {
+    let (r, g, b) = color;
+
+    let quant_r = sign_pow(r / max_value, 0.5);
+    let quant_g = sign_pow(g / max_value, 0.5);
+    let quant_b = sign_pow(b / max_value, 0.5);
+
+    let value = (quant_r * 9.0 + 9.5).floor().clamp(0.0, 18.0) * 19.0 * 19.0
+        + (quant_g * 9.0 + 9.5).floor().clamp(0.0, 18.0) * 19.0
+        + (quant_b * 9.0 + 9.5).floor().clamp(0.0, 18.0);
+
+    encode_int(value as u32, 2)
+}
+
+fn sign_pow(value: f64, exponent: f64) -> f64 {
+    value.signum() * value.abs().powf(exponent)
+}
+
+#[cfg(test)]
+mod blurhash_test {
+    use image::{DynamicImage, RgbImage};
+
+    use super::encode;
+
+    fn solid_color_image(r: u8, g: u8, b: u8) -> DynamicImage {
+        DynamicImage::ImageRgb8(RgbImage::from_pixel(4, 4, image::Rgb([r, g, b])))
+    }
+
+    #[test]
+    fn encode_1x1_components_is_the_average_color() {
+        // With a single (DC-only) component there are no AC terms, so the hash is
+        // fully determined by the average color - a known, hand-computable vector.
+        let image = solid_color_image(255, 0, 0);
+
+        let hash = encode(&image, 1, 1);
+
+        assert_eq!(hash, "00TI:j");
+    }
+
+    #[test]
+    fn encode_length_matches_component_count() {
+        let image = solid_color_image(10, 20, 30);
+
+        // header (2 chars) + DC (4 chars) + AC (2 chars per remaining component)
+        let hash = encode(&image, 3, 2);
+
+        assert_eq!(hash.len(), 2 + 4 + (3 * 2 - 1) * 2);
+    }
+}
@@ -0,0 +1,100 @@
+use std::fmt;
+
+use serde_json::Value;
+
+/// Errors returned by [`crate::sanity_client::SanityClient`] operations
+///
+/// Non-2xx responses are inspected and, where possible, sanity's JSON error envelope
+/// (`{"error": {...}}`) is parsed into the relevant variant instead of surfacing the
+/// raw HTTP response.
+#[derive(Debug)]
+pub enum SanityError {
+    /// The request was rejected for missing or invalid credentials (HTTP 401/403)
+    Unauthorized,
+    /// The dataset is rate-limiting requests (HTTP 429)
+    RateLimited {
+        /// Value of the `Retry-After` header, in seconds, if present
+        retry_after: Option<u64>,
+    },
+    /// The GROQ query could not be parsed or executed; also returned for any other
+    /// non-2xx response whose error envelope has no `items`
+    QueryError {
+        description: String,
+        start: Option<u32>,
+        end: Option<u32>,
+    },
+    /// One or more mutations in a transaction failed to apply
+    MutationFailed { items: Vec<Value> },
+    /// The asset's sniffed format isn't in the configured allow-list, or couldn't be
+    /// determined at all
+    UnsupportedFormat { format: Option<String> },
+    /// The asset is larger than the configured `max_bytes` limit
+    TooLarge { max_bytes: u64, actual_bytes: u64 },
+    /// The asset could not be read from disk or decoded as an image, or the blocking
+    /// task that decodes it failed
+    Io(std::io::Error),
+    /// The request could not be sent, or the response could not be read
+    Transport(reqwest::Error),
+    /// The response body could not be deserialized
+    Deserialize(serde_json::Error),
+}
+
+impl fmt::Display for SanityError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SanityError::Unauthorized => write!(f, "unauthorized: missing or invalid access token"),
+            SanityError::RateLimited { retry_after } => match retry_after {
+                Some(seconds) => write!(f, "rate limited: retry after {}s", seconds),
+                None => write!(f, "rate limited"),
+            },
+            SanityError::QueryError {
+                description,
+                start,
+                end,
+            } => match (start, end) {
+                (Some(start), Some(end)) => {
+                    write!(f, "query error at {}..{}: {}", start, end, description)
+                }
+                _ => write!(f, "query error: {}", description),
+            },
+            SanityError::MutationFailed { items } => {
+                write!(f, "mutation failed for {} item(s)", items.len())
+            }
+            SanityError::UnsupportedFormat { format } => match format {
+                Some(format) => write!(f, "unsupported asset format: {}", format),
+                None => write!(f, "unsupported or undetectable asset format"),
+            },
+            SanityError::TooLarge {
+                max_bytes,
+                actual_bytes,
+            } => write!(
+                f,
+                "asset too large: {} bytes exceeds the {} byte limit",
+                actual_bytes, max_bytes
+            ),
+            SanityError::Io(err) => write!(f, "io error: {}", err),
+            SanityError::Transport(err) => write!(f, "transport error: {}", err),
+            SanityError::Deserialize(err) => write!(f, "deserialize error: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for SanityError {}
+
+impl From<reqwest::Error> for SanityError {
+    fn from(err: reqwest::Error) -> Self {
+        SanityError::Transport(err)
+    }
+}
+
+impl From<std::io::Error> for SanityError {
+    fn from(err: std::io::Error) -> Self {
+        SanityError::Io(err)
+    }
+}
+
+impl From<serde_json::Error> for SanityError {
+    fn from(err: serde_json::Error) -> Self {
+        SanityError::Deserialize(err)
+    }
+}
@@ -0,0 +1,232 @@
+/// Image fit modes supported by the sanity.io image CDN
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Fit {
+    Clip,
+    Crop,
+    Fill,
+    Max,
+    Min,
+    Scale,
+}
+
+impl Fit {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Fit::Clip => "clip",
+            Fit::Crop => "crop",
+            Fit::Fill => "fill",
+            Fit::Max => "max",
+            Fit::Min => "min",
+            Fit::Scale => "scale",
+        }
+    }
+}
+
+/// Crop anchor used together with [`Fit::Crop`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Crop {
+    Top,
+    Bottom,
+    Left,
+    Right,
+    Center,
+    Focalpoint,
+}
+
+impl Crop {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Crop::Top => "top",
+            Crop::Bottom => "bottom",
+            Crop::Left => "left",
+            Crop::Right => "right",
+            Crop::Center => "center",
+            Crop::Focalpoint => "focalpoint",
+        }
+    }
+}
+
+/// Output formats supported by the sanity.io image CDN
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Fmt {
+    Jpg,
+    Png,
+    Webp,
+    Auto,
+}
+
+impl Fmt {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Fmt::Jpg => "jpg",
+            Fmt::Png => "png",
+            Fmt::Webp => "webp",
+            Fmt::Auto => "auto",
+        }
+    }
+}
+
+/// Builds a `cdn.sanity.io/images/{project_id}/{dataset}/{assetId}-{w}x{h}.{ext}` URL
+/// with the on-the-fly transform query params sanity's image pipeline understands.
+///
+/// # Example
+/// ```
+/// use sanity_rs_client::image_url::{Fit, ImageUrlBuilder};
+///
+/// let url = ImageUrlBuilder::new("abc123", "production", "image-abc123-2000x3000-jpg")
+///     .width(400)
+///     .fit(Fit::Crop)
+///     .quality(80)
+///     .build();
+/// ```
+pub struct ImageUrlBuilder {
+    project_id: String,
+    dataset: String,
+    asset_ref: String,
+    params: Vec<(String, String)>,
+}
+
+impl ImageUrlBuilder {
+    /// Creates a new `ImageUrlBuilder` from the project id, dataset and asset reference
+    /// (e.g. `image-abc123-2000x3000-jpg`) to transform
+    pub fn new(project_id: &str, dataset: &str, asset_ref: &str) -> Self {
+        ImageUrlBuilder {
+            project_id: String::from(project_id),
+            dataset: String::from(dataset),
+            asset_ref: String::from(asset_ref),
+            params: Vec::new(),
+        }
+    }
+
+    /// Sets the `w` (width) transform param
+    pub fn width(mut self, width: u32) -> Self {
+        self.params.push((String::from("w"), width.to_string()));
+        self
+    }
+
+    /// Sets the `h` (height) transform param
+    pub fn height(mut self, height: u32) -> Self {
+        self.params.push((String::from("h"), height.to_string()));
+        self
+    }
+
+    /// Sets the `fit` transform param
+    pub fn fit(mut self, fit: Fit) -> Self {
+        self.params
+            .push((String::from("fit"), String::from(fit.as_str())));
+        self
+    }
+
+    /// Sets the `crop` transform param
+    pub fn crop(mut self, crop: Crop) -> Self {
+        self.params
+            .push((String::from("crop"), String::from(crop.as_str())));
+        self
+    }
+
+    /// Sets the `fm` (output format) transform param
+    pub fn format(mut self, format: Fmt) -> Self {
+        self.params
+            .push((String::from("fm"), String::from(format.as_str())));
+        self
+    }
+
+    /// Sets the `q` (quality) transform param, `0..=100`
+    pub fn quality(mut self, quality: u8) -> Self {
+        self.params.push((String::from("q"), quality.to_string()));
+        self
+    }
+
+    /// Sets the `blur` transform param, `0..=100`
+    pub fn blur(mut self, blur: u32) -> Self {
+        self.params.push((String::from("blur"), blur.to_string()));
+        self
+    }
+
+    /// Sets the `rect` transform param, cropping to `x,y,w,h` before any further transform
+    pub fn rect(mut self, x: u32, y: u32, w: u32, h: u32) -> Self {
+        self.params
+            .push((String::from("rect"), format!("{},{},{},{}", x, y, w, h)));
+        self
+    }
+
+    /// Sets the `dpr` (device pixel ratio) transform param, `1.0..=3.0`
+    pub fn dpr(mut self, dpr: f32) -> Self {
+        self.params.push((String::from("dpr"), dpr.to_string()));
+        self
+    }
+
+    /// Turns an asset reference of the form `image-{assetId}-{w}x{h}-{ext}` into the
+    /// `{assetId}-{w}x{h}.{ext}` filename segment of the CDN URL
+    fn asset_path(&self) -> String {
+        let parts: Vec<&str> = self.asset_ref.split('-').collect();
+
+        if parts.len() < 3 {
+            return self.asset_ref.clone();
+        }
+
+        let ext = parts.last().unwrap();
+        let name_parts = &parts[1..parts.len() - 1];
+
+        format!("{}.{}", name_parts.join("-"), ext)
+    }
+
+    /// Builds the final CDN URL with all configured transform query params
+    pub fn build(self) -> String {
+        let path = self.asset_path();
+
+        let url = format!(
+            "https://cdn.sanity.io/images/{}/{}/{}",
+            self.project_id, self.dataset, path
+        );
+
+        if self.params.is_empty() {
+            return url;
+        }
+
+        let query = self
+            .params
+            .iter()
+            .map(|(k, v)| format!("{}={}", k, v))
+            .collect::<Vec<String>>()
+            .join("&");
+
+        format!("{}?{}", url, query)
+    }
+}
+
+#[cfg(test)]
+mod image_url_test {
+    use super::{Crop, Fit, Fmt, ImageUrlBuilder};
+
+    #[test]
+    fn build_without_transforms_returns_bare_url() {
+        let url = ImageUrlBuilder::new("abc123", "production", "image-deadbeef-2000x3000-jpg").build();
+
+        assert_eq!(
+            url,
+            "https://cdn.sanity.io/images/abc123/production/deadbeef-2000x3000.jpg"
+        );
+    }
+
+    #[test]
+    fn build_appends_transforms_in_call_order() {
+        let url = ImageUrlBuilder::new("abc123", "production", "image-deadbeef-2000x3000-jpg")
+            .width(400)
+            .height(300)
+            .fit(Fit::Crop)
+            .crop(Crop::Center)
+            .format(Fmt::Webp)
+            .quality(80)
+            .blur(20)
+            .rect(0, 0, 100, 100)
+            .dpr(2.0)
+            .build();
+
+        assert_eq!(
+            url,
+            "https://cdn.sanity.io/images/abc123/production/deadbeef-2000x3000.jpg?\
+w=400&h=300&fit=crop&crop=center&fm=webp&q=80&blur=20&rect=0,0,100,100&dpr=2"
+        );
+    }
+}
@@ -0,0 +1,7 @@
+pub mod blurhash;
+pub mod config;
+pub mod error;
+pub mod image_url;
+pub mod patch;
+pub mod sanity_client;
+pub mod upload;
@@ -0,0 +1,280 @@
+use serde_json::{json, Value};
+
+use crate::sanity_client::{Mutation, Mutations};
+
+/// Position at which `PatchBuilder::insert` places new items relative to `at`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InsertPosition {
+    Before,
+    After,
+    Replace,
+}
+
+impl InsertPosition {
+    fn as_key(&self) -> &'static str {
+        match self {
+            InsertPosition::Before => "before",
+            InsertPosition::After => "after",
+            InsertPosition::Replace => "replace",
+        }
+    }
+}
+
+/// Fluently builds a sanity `patch` mutation for a single document, matching the
+/// operations sanity's [HTTP mutation API](https://www.sanity.io/docs/http-mutations#958a1adcfc3b)
+/// exposes: `set`, `setIfMissing`, `unset`, `inc`, `dec`, `insert` and `ifRevisionID`.
+///
+/// # Example
+/// ```
+/// use sanity_rs_client::patch::PatchBuilder;
+///
+/// let patch = PatchBuilder::new("author-1")
+///     .set("name", "Random".into())
+///     .inc("views", 1.0)
+///     .build();
+/// ```
+pub struct PatchBuilder {
+    id: String,
+    set: serde_json::Map<String, Value>,
+    set_if_missing: serde_json::Map<String, Value>,
+    unset: Vec<String>,
+    inc: serde_json::Map<String, Value>,
+    dec: serde_json::Map<String, Value>,
+    insert: Option<Value>,
+    if_revision: Option<String>,
+}
+
+impl PatchBuilder {
+    /// Creates a new `PatchBuilder` for the document with the given id
+    pub fn new(id: &str) -> Self {
+        PatchBuilder {
+            id: String::from(id),
+            set: serde_json::Map::new(),
+            set_if_missing: serde_json::Map::new(),
+            unset: Vec::new(),
+            inc: serde_json::Map::new(),
+            dec: serde_json::Map::new(),
+            insert: None,
+            if_revision: None,
+        }
+    }
+
+    /// Sets `path` to `value`, overwriting any existing value
+    pub fn set(mut self, path: &str, value: Value) -> Self {
+        self.set.insert(String::from(path), value);
+        self
+    }
+
+    /// Sets `path` to `value` only if `path` doesn't already have a value
+    pub fn set_if_missing(mut self, path: &str, value: Value) -> Self {
+        self.set_if_missing.insert(String::from(path), value);
+        self
+    }
+
+    /// Removes the fields at the given paths
+    pub fn unset(mut self, paths: Vec<&str>) -> Self {
+        self.unset.extend(paths.into_iter().map(String::from));
+        self
+    }
+
+    /// Increments `path` by `amount`
+    pub fn inc(mut self, path: &str, amount: f64) -> Self {
+        self.inc.insert(String::from(path), json!(amount));
+        self
+    }
+
+    /// Decrements `path` by `amount`
+    pub fn dec(mut self, path: &str, amount: f64) -> Self {
+        self.dec.insert(String::from(path), json!(amount));
+        self
+    }
+
+    /// Inserts `items` into the array at `at`, `position` relative to the existing entry
+    pub fn insert(mut self, position: InsertPosition, at: &str, items: Vec<Value>) -> Self {
+        self.insert = Some(json!({
+            position.as_key(): at,
+            "items": items,
+        }));
+        self
+    }
+
+    /// Only applies the patch if the document's current revision matches `rev`
+    pub fn if_revision(mut self, rev: &str) -> Self {
+        self.if_revision = Some(String::from(rev));
+        self
+    }
+
+    /// Builds the `Mutation::Patch` for this document
+    pub fn build(self) -> Mutation {
+        let mut patch = json!({ "id": self.id });
+        let map = patch.as_object_mut().unwrap();
+
+        if !self.set.is_empty() {
+            map.insert(String::from("set"), Value::Object(self.set));
+        }
+
+        if !self.set_if_missing.is_empty() {
+            map.insert(
+                String::from("setIfMissing"),
+                Value::Object(self.set_if_missing),
+            );
+        }
+
+        if !self.unset.is_empty() {
+            map.insert(String::from("unset"), json!(self.unset));
+        }
+
+        if !self.inc.is_empty() {
+            map.insert(String::from("inc"), Value::Object(self.inc));
+        }
+
+        if !self.dec.is_empty() {
+            map.insert(String::from("dec"), Value::Object(self.dec));
+        }
+
+        if let Some(insert) = self.insert {
+            map.insert(String::from("insert"), insert);
+        }
+
+        if let Some(if_revision) = self.if_revision {
+            map.insert(String::from("ifRevisionID"), json!(if_revision));
+        }
+
+        Mutation::Patch(patch)
+    }
+}
+
+/// Wraps a set of [`Mutation`]s assembled as a single atomic transaction, with helpers
+/// matching sanity's mutation types so a multi-operation transaction can be built fluently.
+///
+/// # Example
+/// ```
+/// use sanity_rs_client::patch::Transaction;
+/// use serde_json::json;
+///
+/// let transaction = Transaction::new()
+///     .transaction_id("tx-1")
+///     .create(json!({ "_type": "author", "name": "Random" }))
+///     .delete("author-2");
+/// ```
+pub struct Transaction {
+    mutations: Mutations,
+    transaction_id: Option<String>,
+}
+
+impl Transaction {
+    /// Creates a new, empty `Transaction`
+    pub fn new() -> Self {
+        Transaction {
+            mutations: Vec::new(),
+            transaction_id: None,
+        }
+    }
+
+    /// Sets an explicit transaction id
+    pub fn transaction_id(mut self, id: &str) -> Self {
+        self.transaction_id = Some(String::from(id));
+        self
+    }
+
+    /// Adds a `create` mutation for `document`
+    pub fn create(mut self, document: Value) -> Self {
+        self.mutations.push(Mutation::Create(document));
+        self
+    }
+
+    /// Adds a `createOrReplace` mutation for `document`
+    pub fn create_or_replace(mut self, document: Value) -> Self {
+        self.mutations.push(Mutation::CreateOrReplace(document));
+        self
+    }
+
+    /// Adds a `delete` mutation for the document with the given id
+    pub fn delete(mut self, id: &str) -> Self {
+        self.mutations.push(Mutation::Delete(json!({ "id": id })));
+        self
+    }
+
+    /// Adds a `patch` mutation built from `patch`
+    pub fn patch(mut self, patch: PatchBuilder) -> Self {
+        self.mutations.push(patch.build());
+        self
+    }
+
+    /// Returns the explicit transaction id, if one was set
+    pub fn id(&self) -> Option<&str> {
+        self.transaction_id.as_deref()
+    }
+
+    /// Consumes the `Transaction`, returning the assembled `Mutations` ready to pass to
+    /// [`crate::sanity_client::SanityClient::mutate`]
+    pub fn into_mutations(self) -> Mutations {
+        self.mutations
+    }
+}
+
+impl Default for Transaction {
+    fn default() -> Self {
+        Transaction::new()
+    }
+}
+
+#[cfg(test)]
+mod patch_test {
+    use serde_json::json;
+
+    use crate::sanity_client::Mutation;
+
+    use super::{InsertPosition, PatchBuilder, Transaction};
+
+    #[test]
+    fn build_emits_expected_patch_payload() {
+        let mutation = PatchBuilder::new("author-1")
+            .set("name", json!("Random"))
+            .set_if_missing("views", json!(0))
+            .unset(vec!["oldField"])
+            .inc("views", 1.0)
+            .insert(InsertPosition::After, "tags[-1]", vec![json!("new")])
+            .if_revision("rev-1")
+            .build();
+
+        let patch = match mutation {
+            Mutation::Patch(patch) => patch,
+            _ => panic!("expected a Mutation::Patch"),
+        };
+
+        assert_eq!(patch["id"], json!("author-1"));
+        assert_eq!(patch["set"], json!({ "name": "Random" }));
+        assert_eq!(patch["setIfMissing"], json!({ "views": 0 }));
+        assert_eq!(patch["unset"], json!(["oldField"]));
+        assert_eq!(patch["inc"], json!({ "views": 1.0 }));
+        assert_eq!(
+            patch["insert"],
+            json!({ "after": "tags[-1]", "items": ["new"] })
+        );
+        assert_eq!(patch["ifRevisionID"], json!("rev-1"));
+    }
+
+    #[test]
+    fn transaction_collects_mutations_in_order() {
+        let transaction = Transaction::new()
+            .transaction_id("tx-1")
+            .create(json!({ "_type": "author", "name": "Random" }))
+            .delete("author-2");
+
+        assert_eq!(transaction.id(), Some("tx-1"));
+
+        let mutations = transaction.into_mutations();
+        assert_eq!(mutations.len(), 2);
+
+        match &mutations[0] {
+            Mutation::Create(doc) => assert_eq!(doc["name"], json!("Random")),
+            _ => panic!("expected a Mutation::Create"),
+        }
+
+        match &mutations[1] {
+            Mutation::Delete(doc) => assert_eq!(doc["id"], json!("author-2")),
+            _ => panic!("expected a Mutation::Delete"),
+        }
+    }
+}
@@ -1,15 +1,22 @@
 use crate::config::SanityConfig;
+use crate::error::SanityError;
+use crate::upload::{validate_and_sanitize, UploadOptions};
 use reqwest::{
     header::{HeaderMap, HeaderValue},
-    Error, Response,
+    multipart::{Form, Part},
+    Body, Response, StatusCode,
 };
 use serde::{Deserialize, Serialize};
 use serde_json::{json, Value};
-use std::{fs::File, sync::Arc};
-use std::{collections::HashMap, fmt::Debug};
+use std::collections::HashMap;
+use tokio_util::io::ReaderStream;
 
 use urlencoding::encode;
 
+/// Max width/height the source image is downscaled to before computing a BlurHash;
+/// the algorithm only samples a handful of DCT components so full resolution isn't needed
+const BLURHASH_THUMBNAIL_SIZE: u32 = 100;
+
 pub type Mutations = Vec<Mutation>;
 
 #[derive(Serialize, Deserialize)]
@@ -70,6 +77,8 @@ enum Operation {
     Mutate,
     /// Image upload
     Images,
+    /// File upload
+    Files,
 }
 
 /// Represents Content to be modified by client
@@ -80,6 +89,15 @@ enum Content {
     Assets,
 }
 
+/// Represents the kind of asset being uploaded via [`SanityClient::upload_asset`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AssetType {
+    /// Uploaded to the `/assets/images/{dataset}` endpoint
+    Image,
+    /// Uploaded to the `/assets/files/{dataset}` endpoint
+    File,
+}
+
 impl SanityClient {
     /// Create a new `SanityClient` with provied `SanityConfig`
     pub fn new(config: SanityConfig) -> SanityClient {
@@ -108,6 +126,54 @@ impl SanityClient {
         return headers;
     }
 
+    /// Inspects the status of a response and, on non-2xx, parses sanity's JSON error
+    /// envelope (`{"error": {...}}`) into the relevant `SanityError` variant
+    async fn handle_response(response: Response) -> Result<Response, SanityError> {
+        let status = response.status();
+
+        if status.is_success() {
+            return Ok(response);
+        }
+
+        if status == StatusCode::UNAUTHORIZED || status == StatusCode::FORBIDDEN {
+            return Err(SanityError::Unauthorized);
+        }
+
+        if status == StatusCode::TOO_MANY_REQUESTS {
+            let retry_after = response
+                .headers()
+                .get(reqwest::header::RETRY_AFTER)
+                .and_then(|value| value.to_str().ok())
+                .and_then(|value| value.parse::<u64>().ok());
+
+            return Err(SanityError::RateLimited { retry_after });
+        }
+
+        let body: Value = response.json().await?;
+        let error = body.get("error").cloned().unwrap_or(body);
+
+        if let Some(items) = error.get("items").and_then(|items| items.as_array()) {
+            return Err(SanityError::MutationFailed {
+                items: items.clone(),
+            });
+        }
+
+        let description = error
+            .get("description")
+            .and_then(|d| d.as_str())
+            .unwrap_or("unknown error")
+            .to_string();
+
+        let start = error.get("start").and_then(|v| v.as_u64()).map(|v| v as u32);
+        let end = error.get("end").and_then(|v| v.as_u64()).map(|v| v as u32);
+
+        Err(SanityError::QueryError {
+            description,
+            start,
+            end,
+        })
+    }
+
     /// A function which returns a relevant sanity.io url
     ///
     /// Example url `https://[projectId].api.sanity.io/v2021/06/07/data/query/[dataset]`
@@ -140,6 +206,9 @@ impl SanityClient {
             Operation::Images => {
                 _operation = "images";
             }
+            Operation::Files => {
+                _operation = "files";
+            }
         }
 
         format!(
@@ -187,7 +256,7 @@ impl SanityClient {
         &self,
         mutations: Mutations,
         query: &Vec<(String, Value)>,
-    ) -> Result<reqwest::Response, Error> {
+    ) -> Result<reqwest::Response, SanityError> {
         let url = self.url(Content::Data, Operation::Mutate);
         let client = reqwest::Client::new();
         let json = json!({ "mutations": mutations });
@@ -202,40 +271,160 @@ impl SanityClient {
             .headers(headers)
             .body(body)
             .send()
-            .await;
+            .await?;
 
-        response
+        SanityClient::handle_response(response).await
     }
 
-    /// Uploads a single image to sanity dataset
+    /// Executes a [`crate::patch::Transaction`] as a single atomic mutate request
+    ///
+    /// Behaves like [`SanityClient::mutate`], additionally sending the transaction's
+    /// `transactionId` (if one was set) alongside the mutations.
+    pub async fn mutate_transaction(
+        &self,
+        transaction: crate::patch::Transaction,
+        query: &Vec<(String, Value)>,
+    ) -> Result<reqwest::Response, SanityError> {
+        let url = self.url(Content::Data, Operation::Mutate);
+        let client = reqwest::Client::new();
+
+        let transaction_id = transaction.id().map(String::from);
+        let mutations = transaction.into_mutations();
+
+        let mut json = json!({ "mutations": mutations });
+        if let Some(transaction_id) = transaction_id {
+            json["transactionId"] = json!(transaction_id);
+        }
+
+        let headers = self.build_headers();
+
+        let body = serde_json::to_string(&json).unwrap();
+
+        let response = client
+            .post(url)
+            .query(&query)
+            .headers(headers)
+            .body(body)
+            .send()
+            .await?;
+
+        SanityClient::handle_response(response).await
+    }
+
+    /// Uploads an asset (image or file) to the sanity dataset
+    ///
+    /// `file_path` is the path to the asset on disk. Without `options`, the file is
+    /// streamed straight off disk into the request body so large uploads don't get
+    /// buffered into memory; with `options`, the whole file is read into memory first.
+    ///
+    /// `asset_type` selects whether the asset is pushed to the `/assets/images/{dataset}`
+    /// or `/assets/files/{dataset}` endpoint.
+    ///
+    /// `content_type` is the MIME type sent for the uploaded part. If `None`, it is
+    /// guessed from the file's extension. It is ignored when `options` is given; the
+    /// MIME type of the sniffed format is sent instead.
+    ///
+    /// `options`, if given, sniffs the asset's real format from its magic bytes, enforces
+    /// the configured allow-list / size limit, and, if `strip_metadata` is set, strips
+    /// embedded metadata (EXIF/XMP) before upload. Passing `None` skips validation and streams the file straight off
+    /// disk without buffering it into memory.
     ///
-    /// `file` is a file path to required image
-    ///  
-    /// NOTE: this is not as async function
-    /// 
-    /// I couldn't figure out how to upload file with reqwest in an async context, it didn't work
-    /// 
-    /// **any help would be appreciated**
     /// # Example
     /// ```
-    ///     let client = SanityClient::new(config) //relevant config;
-    ///     
-    ///     let response = client.upload_image(String::from("./images/image.png"));
+    ///     let client = SanityClient::new(config); //relevant config;
+    ///
+    ///     let response = client
+    ///         .upload_asset(String::from("./images/image.png"), AssetType::Image, None, None)
+    ///         .await;
     /// ```
-    pub fn upload_image(&self, file: String) -> Result<reqwest::blocking::Response, Error> {
-        let clone = Arc::new(self);
-        
-        let url = self.url(Content::Assets, Operation::Images);
+    pub async fn upload_asset(
+        &self,
+        file_path: String,
+        asset_type: AssetType,
+        content_type: Option<String>,
+        options: Option<UploadOptions>,
+    ) -> Result<Value, SanityError> {
+        let url = match asset_type {
+            AssetType::Image => self.url(Content::Assets, Operation::Images),
+            AssetType::File => self.url(Content::Assets, Operation::Files),
+        };
+
+        let file_name = file_path
+            .rsplit('/')
+            .next()
+            .unwrap_or(&file_path)
+            .to_string();
+
+        let (part, mime) = match options {
+            Some(options) => {
+                let bytes = tokio::fs::read(&file_path).await?;
+                let (sanitized, detected) = validate_and_sanitize(&file_path, bytes, &options)?;
+
+                (Part::bytes(sanitized), String::from(detected.mime()))
+            }
+            None => {
+                let file = tokio::fs::File::open(&file_path).await?;
+                let stream = ReaderStream::new(file);
 
-        let r_client = reqwest::blocking::Client::new();
+                let mime = content_type.unwrap_or_else(|| {
+                    mime_guess::from_path(&file_path)
+                        .first_or_octet_stream()
+                        .to_string()
+                });
 
-        let file = File::open(file).expect("Invalid File");
+                (Part::stream(Body::wrap_stream(stream)), mime)
+            }
+        };
+
+        let part = part.file_name(file_name).mime_str(mime.as_str())?;
+
+        let form = Form::new().part("file", part);
 
         let headers = self.build_headers();
+        let client = reqwest::Client::new();
 
-        let response = r_client.post(url).headers(headers).body(file).send();
+        let response = client.post(url).headers(headers).multipart(form).send().await?;
+        let response = SanityClient::handle_response(response).await?;
 
-        return response;
+        Ok(response.json::<Value>().await?)
+    }
+
+    /// Uploads an image, additionally computing a [BlurHash](https://blurha.sh) placeholder
+    /// so consumers can render a blurred preview before the full image loads.
+    ///
+    /// `components_x` and `components_y` control the BlurHash detail level and must each be
+    /// in `1..=9`; `4, 3` is a reasonable default.
+    ///
+    /// Returns the uploaded asset document alongside the BlurHash string.
+    pub async fn upload_image_with_blurhash(
+        &self,
+        file_path: String,
+        components_x: u32,
+        components_y: u32,
+    ) -> Result<(Value, String), SanityError> {
+        let decode_path = file_path.clone();
+
+        // Decoding and encoding run on a blocking thread: BlurHash only needs a handful
+        // of samples per component, so the image is downscaled to a thumbnail first
+        // rather than running the per-pixel cosine sum over the full resolution photo
+        // on the async runtime thread.
+        let hash = tokio::task::spawn_blocking(move || -> Result<String, SanityError> {
+            let image = image::open(&decode_path).map_err(|err| {
+                SanityError::Io(std::io::Error::new(std::io::ErrorKind::Other, err))
+            })?;
+
+            let thumbnail = image.thumbnail(BLURHASH_THUMBNAIL_SIZE, BLURHASH_THUMBNAIL_SIZE);
+
+            Ok(crate::blurhash::encode(&thumbnail, components_x, components_y))
+        })
+        .await
+        .map_err(|err| SanityError::Io(std::io::Error::new(std::io::ErrorKind::Other, err)))??;
+
+        let asset = self
+            .upload_asset(file_path, AssetType::Image, None, None)
+            .await?;
+
+        Ok((asset, hash))
     }
 
     ///Execute a fetch query on sanity.io
@@ -259,7 +448,7 @@ impl SanityClient {
     ///     }
     /// };
     /// ```
-    pub async fn fetch(&self, query: Query) -> Result<Response, Error> {
+    pub async fn fetch(&self, query: Query) -> Result<Response, SanityError> {
         let url: String = self.url(Content::Data, Operation::Query);
 
         let url = format!("{}?query={}", url, encode(query.query.as_str()));
@@ -279,9 +468,9 @@ impl SanityClient {
 
         let response = r_client.get(url).headers(headers).query(&q_array);
 
-        let response = response.send().await;
+        let response = response.send().await?;
 
-        response
+        SanityClient::handle_response(response).await
     }
 }
 
@@ -294,6 +483,7 @@ mod client_test {
     use serde_json::{Number, Value};
     use urlencoding::encode;
 
+    use super::AssetType;
     use super::Mutation;
     use super::Query;
     use super::SanityClient;
@@ -313,20 +503,17 @@ mod client_test {
         return s_client;
     }
 
-    #[test]
+    #[tokio::test]
     #[ignore]
-    fn upload_image_test() {
+    async fn upload_asset_test() {
         let s_client = _prepare_client();
 
-        let response = s_client.upload_image(String::from("image.jpg"));
+        let response = s_client
+            .upload_asset(String::from("image.jpg"), AssetType::Image, None, None)
+            .await;
 
-        if let Ok(data) = response {
-            if let Ok(text) = data.text() {
-                println!("{}", text);
-                assert!(text.len() > 0, "Invalid response received");
-            } else {
-                panic!("Invalid Response body");
-            }
+        if let Ok(asset) = response {
+            assert!(asset.is_object(), "Invalid response received");
         } else {
             panic!("Invalid Response {}", response.unwrap_err());
         }
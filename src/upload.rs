@@ -0,0 +1,154 @@
+use crate::error::SanityError;
+
+/// Image formats recognized when sniffing an upload's magic bytes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImageFormat {
+    Jpg,
+    Png,
+    Webp,
+    Gif,
+}
+
+impl ImageFormat {
+    fn as_str(&self) -> &'static str {
+        match self {
+            ImageFormat::Jpg => "jpg",
+            ImageFormat::Png => "png",
+            ImageFormat::Webp => "webp",
+            ImageFormat::Gif => "gif",
+        }
+    }
+
+    fn from_image_crate(format: image::ImageFormat) -> Option<Self> {
+        match format {
+            image::ImageFormat::Jpeg => Some(ImageFormat::Jpg),
+            image::ImageFormat::Png => Some(ImageFormat::Png),
+            image::ImageFormat::WebP => Some(ImageFormat::Webp),
+            image::ImageFormat::Gif => Some(ImageFormat::Gif),
+            _ => None,
+        }
+    }
+
+    /// Maps a file extension (case-insensitive, without the leading `.`) to the format
+    /// it claims to be, if recognized
+    fn from_extension(ext: &str) -> Option<Self> {
+        match ext.to_lowercase().as_str() {
+            "jpg" | "jpeg" => Some(ImageFormat::Jpg),
+            "png" => Some(ImageFormat::Png),
+            "webp" => Some(ImageFormat::Webp),
+            "gif" => Some(ImageFormat::Gif),
+            _ => None,
+        }
+    }
+
+    /// The MIME type for this sniffed format, to send as the upload's `Content-Type`
+    /// instead of trusting a (possibly lying) file extension
+    pub fn mime(&self) -> &'static str {
+        match self {
+            ImageFormat::Jpg => "image/jpeg",
+            ImageFormat::Png => "image/png",
+            ImageFormat::Webp => "image/webp",
+            ImageFormat::Gif => "image/gif",
+        }
+    }
+}
+
+/// Options controlling validation and sanitization of an asset before it's uploaded,
+/// the way pict-rs validates and strips incoming uploads
+#[derive(Debug, Clone, Default)]
+pub struct UploadOptions {
+    /// Strip embedded metadata (EXIF GPS/orientation, XMP) by re-encoding the image
+    strip_metadata: bool,
+    /// Sniffed formats that are allowed to be uploaded; empty means "allow any recognized format"
+    allowed_formats: Vec<ImageFormat>,
+    /// Reject uploads larger than this many bytes
+    max_bytes: Option<u64>,
+}
+
+impl UploadOptions {
+    /// Creates a new, permissive `UploadOptions` (no stripping, no format restriction, no size limit)
+    pub fn new() -> Self {
+        UploadOptions::default()
+    }
+
+    /// Strips embedded metadata (EXIF/XMP) by re-encoding the image before upload
+    pub fn strip_metadata(mut self, strip: bool) -> Self {
+        self.strip_metadata = strip;
+        self
+    }
+
+    /// Restricts uploads to the given sniffed formats
+    pub fn allowed_formats(mut self, formats: Vec<ImageFormat>) -> Self {
+        self.allowed_formats = formats;
+        self
+    }
+
+    /// Rejects uploads larger than `max_bytes`
+    pub fn max_bytes(mut self, max_bytes: u64) -> Self {
+        self.max_bytes = Some(max_bytes);
+        self
+    }
+}
+
+/// Sniffs the real format of `bytes` from its magic bytes, validates it against
+/// `options` and against the extension claimed by `file_path` (rejecting a mismatch
+/// as a lying extension), and strips embedded metadata by re-encoding the image if
+/// `options.strip_metadata` is set.
+///
+/// Returns the (possibly re-encoded) bytes to upload along with the sniffed format
+/// (so callers can derive the `Content-Type` from it instead of the file path), or a
+/// `SanityError::UnsupportedFormat` / `SanityError::TooLarge` if validation fails.
+pub fn validate_and_sanitize(
+    file_path: &str,
+    bytes: Vec<u8>,
+    options: &UploadOptions,
+) -> Result<(Vec<u8>, ImageFormat), SanityError> {
+    if let Some(max_bytes) = options.max_bytes {
+        let actual_bytes = bytes.len() as u64;
+        if actual_bytes > max_bytes {
+            return Err(SanityError::TooLarge {
+                max_bytes,
+                actual_bytes,
+            });
+        }
+    }
+
+    let detected_raw = image::guess_format(&bytes)
+        .map_err(|_| SanityError::UnsupportedFormat { format: None })?;
+
+    let detected = ImageFormat::from_image_crate(detected_raw)
+        .ok_or(SanityError::UnsupportedFormat { format: None })?;
+
+    if !options.allowed_formats.is_empty() && !options.allowed_formats.contains(&detected) {
+        return Err(SanityError::UnsupportedFormat {
+            format: Some(detected.as_str().to_string()),
+        });
+    }
+
+    let claimed_extension = file_path.rsplit('.').next().and_then(ImageFormat::from_extension);
+    if let Some(claimed) = claimed_extension {
+        if claimed != detected {
+            return Err(SanityError::UnsupportedFormat {
+                format: Some(detected.as_str().to_string()),
+            });
+        }
+    }
+
+    if !options.strip_metadata {
+        return Ok((bytes, detected));
+    }
+
+    let dynamic_image = image::load_from_memory_with_format(&bytes, detected_raw)
+        .map_err(|_| SanityError::UnsupportedFormat {
+            format: Some(detected.as_str().to_string()),
+        })?;
+
+    let mut sanitized = Vec::new();
+    dynamic_image
+        .write_to(&mut std::io::Cursor::new(&mut sanitized), detected_raw)
+        .map_err(|_| SanityError::UnsupportedFormat {
+            format: Some(detected.as_str().to_string()),
+        })?;
+
+    Ok((sanitized, detected))
+}